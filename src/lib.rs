@@ -26,13 +26,16 @@
 
 use std::{
     borrow::{Borrow, Cow},
+    ffi::{CStr, CString},
     fmt,
     io::{self, BufRead},
-    ops::Deref,
+    ops::{Deref, Index, Range},
+    rc::Rc,
+    sync::Arc,
 };
 
 use encoding_rs::WINDOWS_1252;
-use memchr::memchr;
+use memchr::{memchr, memmem};
 
 #[repr(transparent)]
 #[derive(Ord, PartialOrd, Eq, PartialEq)]
@@ -72,6 +75,36 @@ impl Latin1String {
         }
     }
 
+    /// Create a new instance from a rust string, using strict ISO-8859-1 encoding
+    ///
+    /// Unlike [`encode`][Latin1String::encode], which goes through `encoding_rs`'s
+    /// WINDOWS-1252 mapping and silently escapes unmappable codepoints as HTML entities,
+    /// this maps each `char` directly to its byte (`U+00NN` -> `0xNN`) and fails with
+    /// [`EncodeError`] at the first codepoint that doesn't fit in a single byte or is
+    /// the nul character `U+0000`, which this type cannot hold.
+    ///
+    /// ```
+    /// use latin1str::Latin1String;
+    ///
+    /// let s = Latin1String::encode_iso_8859_1("Frühling").unwrap();
+    /// assert_eq!(s.as_bytes(), b"Fr\xFChling");
+    ///
+    /// assert!(Latin1String::encode_iso_8859_1("日本語").is_err());
+    /// ```
+    pub fn encode_iso_8859_1(string: &str) -> Result<Self, EncodeError> {
+        let mut bytes = Vec::with_capacity(string.len());
+        for (index, c) in string.char_indices() {
+            let code = c as u32;
+            if code == 0 || code > 0xFF {
+                return Err(EncodeError { index });
+            }
+            bytes.push(code as u8);
+        }
+        Ok(Self {
+            inner: bytes.into_boxed_slice(),
+        })
+    }
+
     /// Create a new instance by reading from a [`BufRead`] until a null terminator is found
     ///
     /// ```
@@ -94,6 +127,70 @@ impl Latin1String {
             inner: string.into_boxed_slice(),
         })
     }
+
+    /// Create a new instance from a [`CStr`], reusing its nul-free bytes
+    ///
+    /// ```
+    /// use std::ffi::CStr;
+    /// use latin1str::Latin1String;
+    ///
+    /// let c = CStr::from_bytes_with_nul(b"Hello World!\0").unwrap();
+    /// let s = Latin1String::from_c_str(c);
+    /// assert_eq!(s.as_bytes(), b"Hello World!");
+    /// ```
+    pub fn from_c_str(c: &CStr) -> Self {
+        Self {
+            inner: c.to_bytes().into(),
+        }
+    }
+
+    /// Create a new instance from a [`CString`], reusing its nul-free bytes without re-scanning
+    ///
+    /// ```
+    /// use std::ffi::CString;
+    /// use latin1str::Latin1String;
+    ///
+    /// let c = CString::new(b"Hello World!".to_vec()).unwrap();
+    /// let s = Latin1String::from_c_string(c);
+    /// assert_eq!(s.as_bytes(), b"Hello World!");
+    /// ```
+    pub fn from_c_string(c: CString) -> Self {
+        Self {
+            inner: c.into_bytes().into_boxed_slice(),
+        }
+    }
+
+    /// Convert into a reference-counted [`Rc<Latin1Str>`] without re-encoding
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use latin1str::{Latin1Str, Latin1String};
+    ///
+    /// let s = Latin1String::encode("Hello World!").into_owned();
+    /// let rc: Rc<Latin1Str> = s.into_rc();
+    /// assert_eq!(rc.as_bytes(), b"Hello World!");
+    /// ```
+    pub fn into_rc(self) -> Rc<Latin1Str> {
+        let rc: Rc<[u8]> = Rc::from(self.inner);
+        // SAFETY: `Latin1Str` is `#[repr(transparent)]` over `[u8]`
+        unsafe { Rc::from_raw(Rc::into_raw(rc) as *const Latin1Str) }
+    }
+
+    /// Convert into a reference-counted [`Arc<Latin1Str>`] without re-encoding
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use latin1str::{Latin1Str, Latin1String};
+    ///
+    /// let s = Latin1String::encode("Hello World!").into_owned();
+    /// let arc: Arc<Latin1Str> = s.into_arc();
+    /// assert_eq!(arc.as_bytes(), b"Hello World!");
+    /// ```
+    pub fn into_arc(self) -> Arc<Latin1Str> {
+        let arc: Arc<[u8]> = Arc::from(self.inner);
+        // SAFETY: `Latin1Str` is `#[repr(transparent)]` over `[u8]`
+        unsafe { Arc::from_raw(Arc::into_raw(arc) as *const Latin1Str) }
+    }
 }
 
 impl Borrow<Latin1Str> for Latin1String {
@@ -122,6 +219,22 @@ impl From<&Latin1Str> for Latin1String {
     }
 }
 
+impl From<&Latin1Str> for Rc<Latin1Str> {
+    fn from(s: &Latin1Str) -> Rc<Latin1Str> {
+        let rc: Rc<[u8]> = Rc::from(s.as_bytes());
+        // SAFETY: `Latin1Str` is `#[repr(transparent)]` over `[u8]`
+        unsafe { Rc::from_raw(Rc::into_raw(rc) as *const Latin1Str) }
+    }
+}
+
+impl From<&Latin1Str> for Arc<Latin1Str> {
+    fn from(s: &Latin1Str) -> Arc<Latin1Str> {
+        let arc: Arc<[u8]> = Arc::from(s.as_bytes());
+        // SAFETY: `Latin1Str` is `#[repr(transparent)]` over `[u8]`
+        unsafe { Arc::from_raw(Arc::into_raw(arc) as *const Latin1Str) }
+    }
+}
+
 #[repr(transparent)]
 #[derive(PartialEq, PartialOrd, Eq, Ord)]
 /// A borrowed latin-1 encoded string (like `&str`)
@@ -220,7 +333,7 @@ impl Latin1Str {
     }
 
     /// Decode the string
-    /// 
+    ///
     /// ```
     /// # use latin1str::Latin1Str;
     /// let s = Latin1Str::from_bytes_until_nul(b"Fr\xFChling");
@@ -229,4 +342,348 @@ impl Latin1Str {
     pub fn decode(&self) -> Cow<str> {
         WINDOWS_1252.decode(self.as_bytes()).0
     }
+
+    /// Decode this string as strict ISO-8859-1, where byte `0xNN` always maps to `U+00NN`
+    ///
+    /// Unlike [`decode`][Latin1Str::decode], which follows WINDOWS-1252 and reuses bytes
+    /// `0x80..=0x9F` for punctuation, this is always infallible and byte-for-byte, matching
+    /// the original ISO-8859-1 standard.
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Fr\xFChling");
+    /// assert_eq!(s.decode_iso_8859_1(), "Frühling");
+    ///
+    /// let s = Latin1Str::from_bytes_until_nul(b"\x80");
+    /// assert_eq!(s.decode_iso_8859_1(), "\u{80}");
+    /// ```
+    pub fn decode_iso_8859_1(&self) -> Cow<'_, str> {
+        if self.as_bytes().is_ascii() {
+            // SAFETY: every ASCII byte is also valid UTF-8
+            Cow::Borrowed(unsafe { std::str::from_utf8_unchecked(self.as_bytes()) })
+        } else {
+            Cow::Owned(self.as_bytes().iter().map(|&b| char::from(b)).collect())
+        }
+    }
+
+    /// Returns an iterator over the [`char`]s of this string
+    ///
+    /// Every WINDOWS-1252 byte decodes to exactly one scalar value, so this is a cheap
+    /// per-byte table lookup and never allocates, unlike [`decode`][Latin1Str::decode].
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Fr\xFChling");
+    /// assert_eq!(s.chars().collect::<String>(), "Frühling");
+    /// ```
+    pub fn chars(&self) -> Chars<'_> {
+        Chars {
+            bytes: self.as_bytes().iter(),
+        }
+    }
+
+    /// Returns an iterator over the `(byte_offset, char)` pairs of this string
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Fr\xFChling");
+    /// assert_eq!(s.char_indices().nth(2), Some((2, 'ü')));
+    /// ```
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        CharIndices {
+            iter: self.as_bytes().iter().enumerate(),
+        }
+    }
+
+    /// Returns the sub-slice within `range`, or [`None`] if it's out of bounds
+    ///
+    /// Since every byte boundary is a valid character boundary in a single-byte encoding,
+    /// this can never split a codepoint; the only requirement is that `range` lies within
+    /// [`len`][Latin1Str::len].
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// assert_eq!(s.get(0..5).unwrap().as_bytes(), b"Hello");
+    /// assert!(s.get(0..100).is_none());
+    /// ```
+    pub fn get(&self, range: Range<usize>) -> Option<&Latin1Str> {
+        self.as_bytes()
+            .get(range)
+            // SAFETY: a sub-slice of a nul-free slice is itself nul-free
+            .map(|bytes| unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
+    /// Appends a trailing nul byte and returns this string as a [`CString`]
+    ///
+    /// Since `Latin1Str` never contains interior nul bytes, this can never fail, unlike
+    /// [`CString::new`].
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// assert_eq!(s.to_c_string().as_bytes_with_nul(), b"Hello World!\0");
+    /// ```
+    pub fn to_c_string(&self) -> CString {
+        // SAFETY: `Latin1Str` never contains interior nul bytes
+        unsafe { CString::from_vec_unchecked(self.as_bytes().to_vec()) }
+    }
+
+    /// Borrow a `&str` as a `&Latin1Str` without allocating or re-encoding
+    ///
+    /// Every ASCII byte (`0x01`\u{2013}`0x7F`) has the same representation in UTF-8 and
+    /// WINDOWS-1252, so an all-ASCII `&str` can be reinterpreted in place. This fails as
+    /// soon as a byte `>= 0x80` (or the nul byte this crate forbids) is found, unlike
+    /// [`Latin1String::encode`], which always goes through `encoding_rs` and may allocate.
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::try_from_ascii("Hello World!").unwrap();
+    /// assert_eq!(s.as_bytes(), b"Hello World!");
+    ///
+    /// let err = Latin1Str::try_from_ascii("Frühling").unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 2);
+    /// ```
+    pub fn try_from_ascii(s: &str) -> Result<&Self, NonAsciiError> {
+        match s.as_bytes().iter().position(|&b| b == 0x00 || b >= 0x80) {
+            Some(index) => Err(NonAsciiError { index }),
+            // SAFETY: every byte is ASCII and non-nul, checked above
+            None => Ok(unsafe { Self::from_bytes_unchecked(s.as_bytes()) }),
+        }
+    }
+
+    /// Returns the byte index of the first occurrence of `needle`, if any
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// let needle = Latin1Str::from_bytes_until_nul(b"World");
+    /// assert_eq!(s.find(needle), Some(6));
+    /// ```
+    pub fn find(&self, needle: &Latin1Str) -> Option<usize> {
+        memmem::find(self.as_bytes(), needle.as_bytes())
+    }
+
+    /// Returns the byte index of the last occurrence of `needle`, if any
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// let needle = Latin1Str::from_bytes_until_nul(b"o");
+    /// assert_eq!(s.rfind(needle), Some(7));
+    /// ```
+    pub fn rfind(&self, needle: &Latin1Str) -> Option<usize> {
+        memmem::rfind(self.as_bytes(), needle.as_bytes())
+    }
+
+    /// Returns whether `needle` occurs anywhere in this string
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// assert!(s.contains(Latin1Str::from_bytes_until_nul(b"World")));
+    /// assert!(!s.contains(Latin1Str::from_bytes_until_nul(b"world")));
+    /// ```
+    pub fn contains(&self, needle: &Latin1Str) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Returns whether this string starts with `needle`
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// assert!(s.starts_with(Latin1Str::from_bytes_until_nul(b"Hello")));
+    /// assert!(!s.starts_with(Latin1Str::from_bytes_until_nul(b"World")));
+    /// ```
+    pub fn starts_with(&self, needle: &Latin1Str) -> bool {
+        self.as_bytes().starts_with(needle.as_bytes())
+    }
+
+    /// Returns whether this string ends with `needle`
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"Hello World!");
+    /// assert!(s.ends_with(Latin1Str::from_bytes_until_nul(b"World!")));
+    /// assert!(!s.ends_with(Latin1Str::from_bytes_until_nul(b"Hello")));
+    /// ```
+    pub fn ends_with(&self, needle: &Latin1Str) -> bool {
+        self.as_bytes().ends_with(needle.as_bytes())
+    }
+
+    /// Splits this string by occurrences of `sep`, returning an iterator of pieces
+    ///
+    /// Since WINDOWS-1252 is a single-byte encoding, splitting on byte boundaries can
+    /// never produce invalid sub-slices, and the resulting pieces can never contain a nul
+    /// byte since the original string didn't either.
+    ///
+    /// An empty `sep` splits the string into its individual bytes rather than looping
+    /// forever re-matching the same position.
+    ///
+    /// ```
+    /// # use latin1str::Latin1Str;
+    /// let s = Latin1Str::from_bytes_until_nul(b"a,bb,,c");
+    /// let sep = Latin1Str::from_bytes_until_nul(b",");
+    /// let parts: Vec<&[u8]> = s.split(sep).map(Latin1Str::as_bytes).collect();
+    /// assert_eq!(parts, vec![b"a" as &[u8], b"bb", b"", b"c"]);
+    ///
+    /// let empty = Latin1Str::from_bytes_until_nul(b"");
+    /// let parts: Vec<&[u8]> = s.split(empty).map(Latin1Str::as_bytes).collect();
+    /// assert_eq!(parts, vec![b"a" as &[u8], b",", b"b", b"b", b",", b",", b"c"]);
+    /// ```
+    pub fn split<'a>(&'a self, sep: &'a Latin1Str) -> Split<'a> {
+        Split {
+            rest: Some(self),
+            sep,
+        }
+    }
 }
+
+impl Index<Range<usize>> for Latin1Str {
+    type Output = Latin1Str;
+
+    fn index(&self, range: Range<usize>) -> &Latin1Str {
+        self.get(range).expect("byte range out of bounds")
+    }
+}
+
+/// The WINDOWS-1252 mapping for bytes `0x80..=0x9F`, in order
+///
+/// Bytes outside this range map directly to the scalar value of the same number.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+fn decode_windows_1252_byte(b: u8) -> char {
+    match b {
+        0x80..=0x9F => WINDOWS_1252_HIGH[(b - 0x80) as usize],
+        b => char::from(b),
+    }
+}
+
+/// An iterator over the [`char`]s of a [`Latin1Str`]
+///
+/// Created by [`Latin1Str::chars`].
+pub struct Chars<'a> {
+    bytes: std::slice::Iter<'a, u8>,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.bytes.next().copied().map(decode_windows_1252_byte)
+    }
+}
+
+/// An iterator over the `(byte_offset, char)` pairs of a [`Latin1Str`]
+///
+/// Created by [`Latin1Str::char_indices`].
+pub struct CharIndices<'a> {
+    iter: std::iter::Enumerate<std::slice::Iter<'a, u8>>,
+}
+
+impl Iterator for CharIndices<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        self.iter
+            .next()
+            .map(|(index, &b)| (index, decode_windows_1252_byte(b)))
+    }
+}
+
+/// An iterator over sub-slices of a [`Latin1Str`] separated by a separator
+///
+/// Created by [`Latin1Str::split`].
+pub struct Split<'a> {
+    rest: Option<&'a Latin1Str>,
+    sep: &'a Latin1Str,
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = &'a Latin1Str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        if self.sep.is_empty() {
+            // `memmem::find` reports an empty needle as matching at index 0 on every call,
+            // which would otherwise re-split the same position forever. Advance one byte at
+            // a time instead, so the iterator always makes progress and terminates.
+            return if rest.is_empty() {
+                self.rest = None;
+                None
+            } else {
+                let (head, tail) = rest.as_bytes().split_at(1);
+                // SAFETY: both halves are sub-slices of a nul-free `Latin1Str`
+                self.rest = Some(unsafe { Latin1Str::from_bytes_unchecked(tail) });
+                Some(unsafe { Latin1Str::from_bytes_unchecked(head) })
+            };
+        }
+        match rest.find(self.sep) {
+            Some(index) => {
+                // SAFETY: both halves are sub-slices of a nul-free `Latin1Str`
+                let (head, tail) = rest.as_bytes().split_at(index);
+                self.rest = Some(unsafe {
+                    Latin1Str::from_bytes_unchecked(&tail[self.sep.len()..])
+                });
+                Some(unsafe { Latin1Str::from_bytes_unchecked(head) })
+            }
+            None => {
+                self.rest = None;
+                Some(rest)
+            }
+        }
+    }
+}
+
+/// The error returned by [`Latin1Str::try_from_ascii`] when the input isn't plain ASCII
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonAsciiError {
+    index: usize,
+}
+
+impl NonAsciiError {
+    /// The byte offset of the first byte that isn't ASCII (or is a nul byte)
+    pub const fn valid_up_to(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NonAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ASCII byte at index {}", self.index)
+    }
+}
+
+impl std::error::Error for NonAsciiError {}
+
+/// The error returned by [`Latin1String::encode_iso_8859_1`] when a codepoint doesn't fit
+/// or is nul
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeError {
+    index: usize,
+}
+
+impl EncodeError {
+    /// The byte offset into the source `&str` of the first codepoint that couldn't be encoded
+    pub const fn valid_up_to(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "character at index {} is nul or cannot be represented in ISO-8859-1",
+            self.index
+        )
+    }
+}
+
+impl std::error::Error for EncodeError {}